@@ -1,6 +1,5 @@
-use std::collections::{HashSet, HashMap};
+use std::collections::HashMap;
 use std::iter::Peekable;
-use std::borrow::BorrowMut;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Kind {
@@ -8,15 +7,35 @@ pub enum Kind {
     Delim,
 }
 
+// A set of the 26 lowercase letters packed into a `u32`, where bit `n` stands
+// for the letter `'a' + n`.
+pub type LetterSet = u32;
+
+// The single-letter mask for a lowercase letter.
+pub fn char_mask(ch: char) -> LetterSet {
+    1 << (ch as u8 - b'a') as u32
+}
+
+// The lowercase letters present in a letter set, in alphabetical order.
+pub fn mask_letters(mut mask: LetterSet) -> Vec<char> {
+    let mut letters = Vec::new();
+    while mask != 0 {
+        let n = mask.trailing_zeros();
+        letters.push((b'a' + n as u8) as char);
+        mask &= mask - 1;
+    }
+    letters
+}
+
 pub struct Arc {
-    pub letter_set: HashSet<char>,
+    pub letter_set: LetterSet,
     pub next: Node,
 }
 
 impl Arc {
     fn new() -> Self {
         Arc {
-            letter_set: HashSet::new(),
+            letter_set: 0,
             next: Node::new(),
         }
     }
@@ -26,7 +45,7 @@ impl Arc {
 
         match (kind, peek) {
             (Kind::Char(ch), None) => {
-                self.letter_set.insert(*ch);
+                self.letter_set |= char_mask(*ch);
             },
             _ => {
                 self.next.add_word(words);
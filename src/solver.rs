@@ -1,6 +1,6 @@
 use std::collections::{BinaryHeap, HashSet};
 
-use crate::dag::{Graph, Arc, Kind};
+use crate::dag::{Graph, Arc, Kind, LetterSet, char_mask, mask_letters};
 use std::cmp::Ordering;
 
 // Representation of letters on the rack
@@ -27,12 +27,12 @@ impl TileLetter {
     }
 }
 
-// A single placement of letter on a tile with 0-index row and column
+// A single placement of letter on a tile at a signed logical row and column
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub struct TilePlacement {
     letter: TileLetter,
-    row: usize,
-    col: usize,
+    row: isize,
+    col: isize,
 }
 
 // A solution contains the placement of letters and it's total score
@@ -60,21 +60,175 @@ impl PartialEq for Solution {
     }
 }
 
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
 enum Direction {
     TD,
     LR,
 }
 
-type CharSet = HashSet<char>;
-type Pos = (usize, usize);
+impl Direction {
+    // the direction perpendicular to this one, along which cross words run
+    fn perpendicular(&self) -> Direction {
+        match self {
+            Direction::TD => Direction::LR,
+            Direction::LR => Direction::TD,
+        }
+    }
+
+    // unit step (row, col) when walking along this direction
+    fn step(&self) -> (isize, isize) {
+        match self {
+            Direction::TD => (1, 0),
+            Direction::LR => (0, 1),
+        }
+    }
+}
+
+// Premium squares multiply either a single tile's value or the whole word's
+// total, mirroring the layout used by Wordfeud/Scrabble boards
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Premium {
+    Normal,
+    DoubleLetter,
+    TripleLetter,
+    DoubleWord,
+    TripleWord,
+}
+
+impl Premium {
+    fn letter_multiplier(&self) -> usize {
+        match self {
+            Premium::DoubleLetter => 2,
+            Premium::TripleLetter => 3,
+            _ => 1,
+        }
+    }
+
+    fn word_multiplier(&self) -> usize {
+        match self {
+            Premium::DoubleWord => 2,
+            Premium::TripleWord => 3,
+            _ => 1,
+        }
+    }
+}
+
+// Face value of a letter, blanks are scored separately as zero
+fn letter_value(ch: char) -> usize {
+    match ch {
+        'a' | 'e' | 'i' | 'o' | 'u' | 'l' | 'n' | 's' | 't' | 'r' => 1,
+        'd' | 'g' => 2,
+        'b' | 'c' | 'm' | 'p' => 3,
+        'f' | 'h' | 'v' | 'w' | 'y' => 4,
+        'k' => 5,
+        'j' | 'x' => 8,
+        'q' | 'z' => 10,
+        _ => 0,
+    }
+}
+
+// Parse a premium-square layout into a row-major vector. Tokens are whitespace
+// separated, `.` for a normal square and `DL`/`TL`/`DW`/`TW` for the premiums.
+fn parse_layout(layout: &str, rows: usize, cols: usize) -> Result<Vec<Premium>, &'static str> {
+    let mut squares = Vec::with_capacity(rows * cols);
+
+    for line in layout.lines() {
+        let mut in_row = 0;
+        for token in line.split_whitespace() {
+            let premium = match token {
+                "." => Premium::Normal,
+                "DL" => Premium::DoubleLetter,
+                "TL" => Premium::TripleLetter,
+                "DW" => Premium::DoubleWord,
+                "TW" => Premium::TripleWord,
+                _ => return Err("invalid premium token"),
+            };
+            squares.push(premium);
+            in_row += 1;
+        }
+        if in_row != cols {
+            return Err("premium layout does not match board width");
+        }
+    }
+
+    if squares.len() != rows * cols {
+        return Err("premium layout does not match board height");
+    }
+
+    Ok(squares)
+}
+
+// Infer the direction of a play from its tiles: a play whose tiles share a row
+// runs across, otherwise it runs down. A single tile defaults to across.
+fn move_direction(tiles: &[TilePlacement]) -> Direction {
+    if tiles.iter().all(|t| t.row == tiles[0].row) {
+        Direction::LR
+    } else {
+        Direction::TD
+    }
+}
+
+// An auto-extending board axis. Logical coordinates are signed and stable; the
+// backing storage only spans `[offset, offset + size)`, so the board can grow
+// outward in either direction without renumbering existing squares.
+#[derive(Copy, Clone)]
+struct Dimension {
+    offset: isize,
+    size: usize,
+}
+
+impl Dimension {
+    // An axis sized to hold `size` squares, centred on logical coordinate 0
+    fn centered(size: usize) -> Self {
+        Dimension {
+            offset: -(size as isize / 2),
+            size,
+        }
+    }
+
+    // Translate a logical coordinate to its index along the axis
+    fn map(&self, pos: isize) -> usize {
+        (pos - self.offset) as usize
+    }
+
+    fn min(&self) -> isize {
+        self.offset
+    }
+
+    fn max(&self) -> isize {
+        self.offset + self.size as isize - 1
+    }
+
+    // Widen the axis, if needed, so it covers the logical coordinate `pos`
+    fn include(&mut self, pos: isize) {
+        if pos < self.min() {
+            self.size += (self.offset - pos) as usize;
+            self.offset = pos;
+        } else if pos > self.max() {
+            self.size = (pos - self.offset + 1) as usize;
+        }
+    }
+
+    // Pad the axis by one square on each side
+    fn extend(&mut self) {
+        self.offset -= 1;
+        self.size += 2;
+    }
+}
+
+// Every set of letters is a 26-bit mask; see `crate::dag::LetterSet`
+type CharSet = LetterSet;
+type Pos = (isize, isize);
+
+// Mask with every lowercase letter set, used for unconstrained squares
+const FULL_ALPHABET: CharSet = (1 << 26) - 1;
 
 pub struct Solver {
     graph: Graph,
 
-    // dimensions and state of the board
-    rows: usize,
-    cols: usize,
+    // auto-extending board axes (x: columns, y: rows) and board state
+    x_dim: Dimension,
+    y_dim: Dimension,
     board: Vec<Option<TileLetter>>,
 
     // Sets of characters allowed on the given tile
@@ -82,6 +236,9 @@ pub struct Solver {
     // 0: cross sets for left-right plays, 1: cross sets for top-down plays
     cross_sets: (Vec<CharSet>, Vec<CharSet>),
 
+    // Premium-square layout, one entry per square in row-major order
+    layout: Vec<Premium>,
+
     // List of index of candidate anchors
     // Anchors are a set of tiles we can start looking for a legal move and these are potential
     // candidates
@@ -90,43 +247,39 @@ pub struct Solver {
 
 impl Solver {
     pub fn new(rows: usize, cols: usize) -> Result<Self, &'static str> {
-        if rows % 2 == 0 || cols % 2 == 0 {
-            return Err("rows and cols must be odd numbers");
+        if rows == 0 || cols == 0 {
+            return Err("rows and cols must be non-zero");
         }
 
-        let mut lr_cross = Vec::new();
-        let mut td_cross = Vec::new();
-        let charset = (b'a'..=b'z').map(char::from).collect::<HashSet<_>>();
-
-        for _ in 0..rows*cols {
-            lr_cross.push(charset.clone());
-            td_cross.push(charset.clone());
-        }
+        let x_dim = Dimension::centered(cols);
+        let y_dim = Dimension::centered(rows);
 
         let mut solver = Solver {
             graph: Graph::new(),
-            rows,
-            cols,
+            x_dim,
+            y_dim,
             board: vec![None; rows * cols],
-            cross_sets: (lr_cross, td_cross),
+            cross_sets: (vec![FULL_ALPHABET; rows * cols], vec![FULL_ALPHABET; rows * cols]),
+            layout: vec![Premium::Normal; rows * cols],
             candidates: HashSet::new(),
         };
 
-        // the center of the board is the only anchor at the start of the game
-        solver.candidates.insert((rows / 2, cols / 2));
+        // logical coordinate (0, 0) is the centre and the only anchor at the
+        // start of the game; the playable area grows outward from here
+        solver.candidates.insert((0, 0));
 
         Ok(solver)
     }
 
-    fn get_index(&self, row: usize, col: usize) -> usize {
-        (self.cols * row) + col
+    fn get_index(&self, row: isize, col: isize) -> usize {
+        self.x_dim.map(col) + self.x_dim.size * self.y_dim.map(row)
     }
 
-    fn get_cross_set(&self, row: usize, col: usize, dir: &Direction) -> &CharSet {
+    fn get_cross_set(&self, row: isize, col: isize, dir: &Direction) -> CharSet {
         let i = self.get_index(row, col);
         match dir {
-            Direction::LR => &self.cross_sets.0[i],
-            Direction::TD => &self.cross_sets.1[i]
+            Direction::LR => self.cross_sets.0[i],
+            Direction::TD => self.cross_sets.1[i]
         }
     }
 
@@ -134,20 +287,16 @@ impl Solver {
         match dir {
             Direction::LR => {
                 let (mut row, mut col) = *pos;
-                while row > 0 {
-                    if self.candidates.contains(&(row - 1, col)) {
-                        row -= 1;
-                    }
+                while col > self.x_dim.min() && self.candidates.contains(&(row, col - 1)) {
+                    col -= 1;
                 }
 
                 (row, col)
             },
             Direction::TD => {
                 let (mut row, mut col) = *pos;
-                while col > 0 {
-                    if self.candidates.contains(&(row, col - 1)) {
-                        row -= 1;
-                    }
+                while row > self.y_dim.min() && self.candidates.contains(&(row - 1, col)) {
+                    row -= 1;
                 }
 
                 (row, col)
@@ -155,7 +304,7 @@ impl Solver {
         }
     }
 
-    fn compute_candidates(&mut self, placements: &Vec<TilePlacement>) {
+    fn compute_candidates(&mut self, placements: &[TilePlacement]) {
         let mut new_candidates = Vec::new();
 
         for placement in placements {
@@ -166,24 +315,24 @@ impl Solver {
 
             // empty the cross sets for this index
             let i = self.get_index(row, col);
-            self.cross_sets.0[i].clear();
-            self.cross_sets.1[i].clear();
+            self.cross_sets.0[i] = 0;
+            self.cross_sets.1[i] = 0;
 
             // check the tiles surrounding the current placement
             // if those tiles are empty, they can be anchors for next move
-            if row > 0 && self.board[self.get_index(row - 1, col)].is_none() {
+            if row > self.y_dim.min() && self.board[self.get_index(row - 1, col)].is_none() {
                 self.candidates.insert((row - 1, col));
             }
 
-            if row < self.rows - 1 && self.board[self.get_index(row + 1, col)].is_none() {
+            if row < self.y_dim.max() && self.board[self.get_index(row + 1, col)].is_none() {
                 self.candidates.insert((row + 1, col));
             }
 
-            if col > 0 && self.board[self.get_index(row, col - 1)].is_none() {
+            if col > self.x_dim.min() && self.board[self.get_index(row, col - 1)].is_none() {
                 self.candidates.insert((row, col - 1));
             }
 
-            if col < self.cols - 1 && self.board[self.get_index(row, col + 1)].is_none() {
+            if col < self.x_dim.max() && self.board[self.get_index(row, col + 1)].is_none() {
                 self.candidates.insert((row, col + 1));
             }
         }
@@ -194,40 +343,471 @@ impl Solver {
     }
 
     fn compute_cross_sets(&mut self) {
-        // for now, we will iterate through all candidate squares and compute cross sets for them
-        // TODO: might not need to clone here
+        // recompute the cross sets of every anchor square in both directions
         for candidate in self.candidates.clone() {
-            let (row, col) = *candidate;
-            let mut offset = 0;
+            self.walk_tile(candidate.0, candidate.1, &Direction::LR);
+            self.walk_tile(candidate.0, candidate.1, &Direction::TD);
+        }
+    }
 
-            // 1. skip down to the bottom most square and walk up from there
-            while row + offset < self.rows - 1 && self.board[self.get_index(row + offset + 1, col)].is_some() {
-                offset += 1;
+    // Compute the cross-check set for the empty square `(row, col)` when playing
+    // in `dir`: the set of letters that, placed here, complete a legal word with
+    // the tiles directly before and after it in the perpendicular direction.
+    // The result is stored into the matching `cross_sets` vector; a square with
+    // no perpendicular neighbours keeps its full alphabet.
+    fn walk_tile(&mut self, row: isize, col: isize, dir: &Direction) {
+        let (drow, dcol) = dir.perpendicular().step();
+
+        // gather the filled tiles flanking the square, each read outward
+        let before = self.collect_tiles(row, col, -drow, -dcol);
+        let after = self.collect_tiles(row, col, drow, dcol);
+
+        // no perpendicular word touches this square, leave the full alphabet
+        if before.is_empty() && after.is_empty() {
+            return;
+        }
+
+        let mut set: CharSet = 0;
+        for c in (b'a'..=b'z').map(char::from) {
+            if self.cross_check(&before, &after, c) {
+                set |= char_mask(c);
             }
-            if offset > 0 {
-                self.walk_tile(row + offset, col, 0, &Direction::TD)
+        }
+
+        let i = self.get_index(row, col);
+        match dir {
+            Direction::LR => self.cross_sets.0[i] = set,
+            Direction::TD => self.cross_sets.1[i] = set,
+        }
+    }
+
+    // Collect the contiguous filled tiles starting one step away from
+    // `(row, col)` in the `(drow, dcol)` direction, nearest tile first.
+    fn collect_tiles(&self, row: isize, col: isize, drow: isize, dcol: isize) -> Vec<TileLetter> {
+        let mut tiles = Vec::new();
+        let mut r = row + drow;
+        let mut c = col + dcol;
+
+        while r >= self.y_dim.min() && r <= self.y_dim.max()
+            && c >= self.x_dim.min() && c <= self.x_dim.max()
+        {
+            match self.board[self.get_index(r, c)] {
+                Some(tile) => tiles.push(tile),
+                None => break,
             }
+            r += drow;
+            c += dcol;
+        }
 
-            // 2. skip across to the right most square and walk left from there
-            offset = 0;
-            while col + offset < self.cols - 1 && self.board[self.get_index(row, col + offset + 1)].is_some() {
-                offset += 1;
+        tiles
+    }
+
+    // Walk the GADDAG to decide whether `c` placed at the square completes a
+    // legal perpendicular word with `before` (read outward) and `after` tiles.
+    fn cross_check(&self, before: &[TileLetter], after: &[TileLetter], c: char) -> bool {
+        // the hook letter is always read first in the GADDAG path
+        let mut arc = match self.graph.init.next.arcs.get(&Kind::Char(c)) {
+            Some(arc) => arc,
+            None => return false,
+        };
+
+        if after.is_empty() {
+            // word is `before + c`; the farthest prefix tile completes it.
+            // `letter_set` lives on the arc reached *after* consuming that
+            // tile, not the one before it, so the last tile's arc must be
+            // walked too before testing.
+            let (last, rest) = match before.split_last() {
+                Some(split) => split,
+                None => return false,
+            };
+            for tile in rest {
+                arc = match arc.next.arcs.get(&Kind::Char(tile.to_char())) {
+                    Some(arc) => arc,
+                    None => return false,
+                };
             }
-            if offset > 0 {
-                self.walk_tile(row, col + offset, 0, &Direction::LR)
+            arc = match arc.next.arcs.get(&Kind::Char(last.to_char())) {
+                Some(arc) => arc,
+                None => return false,
+            };
+            return arc.letter_set & char_mask(last.to_char()) != 0;
+        }
+
+        // walk the prefix, cross the delimiter, then the suffix up to and
+        // including its last tile, which must complete the word
+        for tile in before {
+            arc = match arc.next.arcs.get(&Kind::Char(tile.to_char())) {
+                Some(arc) => arc,
+                None => return false,
+            };
+        }
+        arc = match arc.next.arcs.get(&Kind::Delim) {
+            Some(arc) => arc,
+            None => return false,
+        };
+        let (last, rest) = after.split_last().unwrap();
+        for tile in rest {
+            arc = match arc.next.arcs.get(&Kind::Char(tile.to_char())) {
+                Some(arc) => arc,
+                None => return false,
+            };
+        }
+        arc = match arc.next.arcs.get(&Kind::Char(last.to_char())) {
+            Some(arc) => arc,
+            None => return false,
+        };
+        arc.letter_set & char_mask(last.to_char()) != 0
+    }
+
+    // Face value of a placed tile, blanks are always worth zero
+    fn tile_value(letter: &TileLetter) -> usize {
+        match letter {
+            TileLetter::Blank(_) => 0,
+            TileLetter::Char(ch) => letter_value(*ch),
+        }
+    }
+
+    // Score a complete play. `placements` is the full main word in board order
+    // (both freshly placed tiles and the board tiles they connect to); a square
+    // is newly placed exactly when the board is still empty there. Premium
+    // squares only count for newly placed tiles.
+    fn score_play(&self, placements: &Vec<TilePlacement>, dir: &Direction) -> usize {
+        let mut total = 0;
+        let mut word_score = 0;
+        let mut word_mult = 1;
+        let mut rack_tiles = 0;
+
+        for placement in placements {
+            let i = self.get_index(placement.row, placement.col);
+            let value = Self::tile_value(&placement.letter);
+
+            if self.board[i].is_none() {
+                rack_tiles += 1;
+                let premium = self.layout[i];
+                word_score += value * premium.letter_multiplier();
+                word_mult *= premium.word_multiplier();
+
+                if let Some(cross) = self.score_cross_word(placement, dir) {
+                    total += cross;
+                }
+            } else {
+                word_score += value;
             }
         }
+
+        total += word_score * word_mult;
+
+        // all seven rack tiles played in a single move earns the bingo bonus
+        if rack_tiles == 7 {
+            total += 50;
+        }
+
+        total
     }
 
-    fn walk_tile(&mut self, row: usize, col: usize, offset: isize, dir: &Direction) {
-        // TODO: complete this
+    // Score the perpendicular word a newly placed tile forms, if any. The new
+    // tile is the only square whose premium applies; the surrounding board tiles
+    // contribute their face value. Returns `None` when the tile stands alone in
+    // the perpendicular direction.
+    fn score_cross_word(&self, placement: &TilePlacement, dir: &Direction) -> Option<usize> {
+        let (drow, dcol) = dir.perpendicular().step();
+
+        let mut neighbours = 0;
+        let mut score = 0;
+
+        // walk both ways along the perpendicular direction, summing board tiles
+        for sign in [-1isize, 1] {
+            let mut row = placement.row + sign * drow;
+            let mut col = placement.col + sign * dcol;
+
+            while row >= self.y_dim.min()
+                && row <= self.y_dim.max()
+                && col >= self.x_dim.min()
+                && col <= self.x_dim.max()
+            {
+                match self.board[self.get_index(row, col)] {
+                    Some(tile) => {
+                        neighbours += 1;
+                        score += Self::tile_value(&tile);
+                    }
+                    None => break,
+                }
+
+                row += sign * drow;
+                col += sign * dcol;
+            }
+        }
+
+        if neighbours == 0 {
+            return None;
+        }
+
+        let premium = self.layout[self.get_index(placement.row, placement.col)];
+        score += Self::tile_value(&placement.letter) * premium.letter_multiplier();
+
+        Some(score * premium.word_multiplier())
     }
 
     pub fn add_dictionary_word(&mut self, word: &str) {
         self.graph.add_word(word)
     }
 
-    pub fn place_tiles(&mut self, placements: Vec<TilePlacement>) {}
+    pub fn place_tiles(&mut self, placements: Vec<TilePlacement>) {
+        self.ensure_capacity(&placements);
+        self.write_tiles(&placements);
+    }
+
+    // Write placements directly into the current grid, without growing it
+    // first, then refresh anchors and cross sets. Used by `place_tiles` after
+    // `ensure_capacity` has made room for the play.
+    fn write_tiles(&mut self, placements: &[TilePlacement]) {
+        for placement in placements {
+            let i = self.get_index(placement.row, placement.col);
+            self.board[i] = Some(placement.letter);
+        }
+
+        self.compute_candidates(placements);
+        self.compute_cross_sets();
+    }
+
+    // Grow the backing grid so that every placement, and the ring of squares
+    // around the play, lies within storage. The board only ever widens, so
+    // plays off the current edge reallocate rather than panic. Plays that
+    // land well inside the current bounds leave the grid untouched.
+    fn ensure_capacity(&mut self, placements: &[TilePlacement]) {
+        let touches_edge = placements.iter().any(|placement| {
+            placement.row <= self.y_dim.min() || placement.row >= self.y_dim.max()
+                || placement.col <= self.x_dim.min() || placement.col >= self.x_dim.max()
+        });
+
+        if !touches_edge {
+            return;
+        }
+
+        let mut x = self.x_dim;
+        let mut y = self.y_dim;
+
+        for placement in placements {
+            x.include(placement.col);
+            y.include(placement.row);
+        }
+
+        // pad by one so the freshly exposed neighbours can become anchors
+        x.extend();
+        y.extend();
+
+        self.reallocate(x, y);
+    }
+
+    // Move the board, cross sets and premium layout into a larger grid,
+    // copying every existing cell to its shifted index.
+    fn reallocate(&mut self, x_dim: Dimension, y_dim: Dimension) {
+        let size = x_dim.size * y_dim.size;
+        let mut board = vec![None; size];
+        let mut lr = vec![FULL_ALPHABET; size];
+        let mut td = vec![FULL_ALPHABET; size];
+        let mut layout = vec![Premium::Normal; size];
+
+        for row in self.y_dim.min()..=self.y_dim.max() {
+            for col in self.x_dim.min()..=self.x_dim.max() {
+                let old = self.get_index(row, col);
+                let new = x_dim.map(col) + x_dim.size * y_dim.map(row);
+                board[new] = self.board[old];
+                lr[new] = self.cross_sets.0[old];
+                td[new] = self.cross_sets.1[old];
+                layout[new] = self.layout[old];
+            }
+        }
+
+        self.x_dim = x_dim;
+        self.y_dim = y_dim;
+        self.board = board;
+        self.cross_sets = (lr, td);
+        self.layout = layout;
+    }
+
+    // Construct a solver from a textual board. `grid` has one row per line, `.`
+    // for an empty square, a lowercase letter for a placed tile and an uppercase
+    // letter for a blank played as that letter. `layout` describes the premium
+    // squares as whitespace-separated tokens per row (`.` normal, plus `DL`,
+    // `TL`, `DW`, `TW`). Board, premium layout and anchors are populated from
+    // the description, but no dictionary exists yet at this point, so
+    // cross-sets are left at the full alphabet; call `recompute_cross_sets`
+    // once the dictionary is loaded to constrain the squares next to tiles.
+    pub fn from_description(grid: &str, layout: &str) -> Result<Self, &'static str> {
+        let lines: Vec<&str> = grid.lines().collect();
+        let rows = lines.len();
+        let cols = lines.first().map(|line| line.chars().count()).unwrap_or(0);
+        if rows == 0 || cols == 0 {
+            return Err("empty board description");
+        }
+
+        // the description occupies logical coordinates (0, 0)..(rows, cols)
+        let mut solver = Solver {
+            graph: Graph::new(),
+            x_dim: Dimension { offset: 0, size: cols },
+            y_dim: Dimension { offset: 0, size: rows },
+            board: vec![None; rows * cols],
+            cross_sets: (vec![FULL_ALPHABET; rows * cols], vec![FULL_ALPHABET; rows * cols]),
+            layout: parse_layout(layout, rows, cols)?,
+            candidates: HashSet::new(),
+        };
+
+        let mut placements = Vec::new();
+        for (row, line) in lines.iter().enumerate() {
+            if line.chars().count() != cols {
+                return Err("ragged board description");
+            }
+            for (col, ch) in line.chars().enumerate() {
+                let letter = match ch {
+                    '.' => continue,
+                    'a'..='z' => TileLetter::Char(ch),
+                    'A'..='Z' => TileLetter::Blank(ch.to_ascii_lowercase()),
+                    _ => return Err("invalid tile in board description"),
+                };
+                placements.push(TilePlacement {
+                    letter,
+                    row: row as isize,
+                    col: col as isize,
+                });
+            }
+        }
+
+        if placements.is_empty() {
+            // an empty board starts with the single centre anchor
+            solver.candidates.insert((rows as isize / 2, cols as isize / 2));
+        } else {
+            for placement in &placements {
+                let i = solver.get_index(placement.row, placement.col);
+                solver.board[i] = Some(placement.letter);
+            }
+            solver.compute_candidates(&placements);
+        }
+
+        Ok(solver)
+    }
+
+    // Recompute the cross-check sets of every anchor against the current
+    // dictionary. `from_description` seeds the board before any words are
+    // known, so callers must invoke this once the dictionary is loaded to
+    // restrict the squares adjacent to its tiles to the letters that form
+    // legal cross words.
+    pub fn recompute_cross_sets(&mut self) {
+        self.compute_cross_sets();
+    }
+
+    // Render the current board as a grid string in the same format parsed by
+    // `from_description`.
+    pub fn to_description(&self) -> String {
+        let mut out = String::with_capacity(self.y_dim.size * (self.x_dim.size + 1));
+        for row in self.y_dim.min()..=self.y_dim.max() {
+            for col in self.x_dim.min()..=self.x_dim.max() {
+                let ch = match self.board[self.get_index(row, col)] {
+                    Some(TileLetter::Char(ch)) => ch,
+                    Some(TileLetter::Blank(ch)) => ch.to_ascii_uppercase(),
+                    None => '.',
+                };
+                out.push(ch);
+            }
+            if row < self.y_dim.max() {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    // Serialize a solution as `<flag><square><word>`: a leading `-` for an
+    // across play or `|` for a down play, the start square as a row letter and
+    // 1-indexed column number, then the word with rack tiles in lowercase and
+    // tiles already on the board in uppercase.
+    pub fn format_move(&self, solution: &Solution) -> String {
+        let mut tiles = solution.placement.clone();
+        let dir = move_direction(&tiles);
+        match dir {
+            Direction::LR => tiles.sort_by_key(|t| t.col),
+            Direction::TD => tiles.sort_by_key(|t| t.row),
+        }
+
+        let start = &tiles[0];
+        let mut out = String::new();
+        out.push(match dir {
+            Direction::LR => '-',
+            Direction::TD => '|',
+        });
+        out.push((b'a' + self.y_dim.map(start.row) as u8) as char);
+        out.push_str(&(self.x_dim.map(start.col) + 1).to_string());
+
+        for tile in &tiles {
+            let ch = tile.letter.to_char();
+            if self.board[self.get_index(tile.row, tile.col)].is_some() {
+                out.push(ch.to_ascii_uppercase());
+            } else {
+                out.push(ch);
+            }
+        }
+
+        out
+    }
+
+    // Parse a move string produced by `format_move` and apply its rack tiles
+    // (the lowercase letters) to the board via `place_tiles`. Tiles already on
+    // the board (uppercase, optionally parenthesized) are left untouched.
+    pub fn apply_move(&mut self, mv: &str) -> Result<(), &'static str> {
+        let mut chars = mv.chars().peekable();
+
+        let dir = match chars.next() {
+            Some('-') => Direction::LR,
+            Some('|') => Direction::TD,
+            _ => return Err("missing direction flag"),
+        };
+
+        // the square is given in display coordinates relative to the current
+        // storage, so shift it back onto the logical coordinate system
+        let row = match chars.next() {
+            Some(ch @ 'a'..='z') => self.y_dim.min() + (ch as u8 - b'a') as isize,
+            _ => return Err("missing row letter"),
+        };
+
+        let mut number = String::new();
+        while let Some(ch) = chars.peek() {
+            if ch.is_ascii_digit() {
+                number.push(*ch);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        let col = self.x_dim.min()
+            + number
+                .parse::<isize>()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or("missing column number")?
+            - 1;
+
+        let (drow, dcol) = dir.step();
+        let mut placements = Vec::new();
+        let mut step = 0isize;
+        for ch in chars {
+            match ch {
+                '(' | ')' => continue,
+                'a'..='z' => {
+                    placements.push(TilePlacement {
+                        letter: TileLetter::Char(ch),
+                        row: row + step * drow,
+                        col: col + step * dcol,
+                    });
+                }
+                'A'..='Z' => { /* already on the board */ }
+                _ => return Err("invalid letter in move"),
+            }
+            step += 1;
+        }
+
+        self.place_tiles(placements);
+        Ok(())
+    }
+
 
     pub fn generate_moves(&mut self, letters: &Vec<RackLetter>) -> BinaryHeap<Solution> {
         let mut solutions = BinaryHeap::new();
@@ -253,16 +833,16 @@ impl Solver {
                              anchor: &Pos,
                              dir: Direction,
                              solutions: &mut BinaryHeap<Solution>) {
-        let mut tiles = MoveGenerator::generate_moves(&self, letters, anchor.0, anchor.1, dir);
+        let tiles = MoveGenerator::generate_moves(&self, letters, anchor.0, anchor.1, dir.clone());
         for placements in tiles {
             for placement in &placements {
                 self.candidates.remove(&(placement.row, placement.col));
             }
 
-            // TODO: Calculate score
+            let score = self.score_play(&placements, &dir);
             solutions.push(Solution {
                 placement: placements,
-                score: 0,
+                score,
             })
         }
     }
@@ -270,14 +850,14 @@ impl Solver {
 
 struct MoveGenerator<'a> {
     solver: &'a Solver,
-    row: usize,
-    col: usize,
+    row: isize,
+    col: isize,
     dir: Direction,
     moves: Vec<Vec<TilePlacement>>,
 }
 
 impl<'a> MoveGenerator<'a> {
-    fn generate_moves(solver: &'a Solver, rack: &Vec<RackLetter>, row: usize, col: usize, dir: Direction) -> Vec<Vec<TilePlacement>> {
+    fn generate_moves(solver: &'a Solver, rack: &Vec<RackLetter>, row: isize, col: isize, dir: Direction) -> Vec<Vec<TilePlacement>> {
         let mut generator = MoveGenerator {
             solver,
             row,
@@ -302,22 +882,22 @@ impl<'a> MoveGenerator<'a> {
             return;
         }
 
-        let mut row = self.row as isize;
-        let mut col = self.col as isize;
+        let mut row = self.row;
+        let mut col = self.col;
         match self.dir {
             Direction::TD => row += offset,
             Direction::LR => col += offset,
         };
 
         // this won't be out of index, the bound will be checked in go on method
-        let cross_set = self.solver.get_cross_set(row as usize, col as usize, &self.dir);
-        if cross_set.is_empty() {
+        let cross_set = self.solver.get_cross_set(row, col, &self.dir);
+        if cross_set == 0 {
             return; // no letter eligible here, return early
         }
 
         for (idx, letter) in (&rack).iter().enumerate() {
             match letter {
-                RackLetter::Char(ch) if cross_set.contains(ch) => {
+                RackLetter::Char(ch) if cross_set & char_mask(*ch) != 0 => {
                     let new_arc = arc.next.arcs.get(&Kind::Char(*ch));
                     let mut new_rack = rack.clone();
                     new_rack.remove(idx);
@@ -325,12 +905,13 @@ impl<'a> MoveGenerator<'a> {
                     self.go_on(TileLetter::Char(*ch), words, offset, new_rack, arc, new_arc);
                 }
                 RackLetter::Blank => {
-                    for playable in cross_set {
-                        let new_arc = arc.next.arcs.get(&Kind::Char(*playable));
+                    // a blank may stand for any letter the cross set still allows
+                    for playable in mask_letters(cross_set) {
+                        let new_arc = arc.next.arcs.get(&Kind::Char(playable));
                         let mut new_rack = rack.clone();
                         new_rack.remove(idx);
 
-                        self.go_on(TileLetter::Blank(*playable), words, offset, new_rack, arc, new_arc);
+                        self.go_on(TileLetter::Blank(playable), words, offset, new_rack, arc, new_arc);
                     }
                 }
                 _ => { /* do nothing */ }
@@ -342,8 +923,8 @@ impl<'a> MoveGenerator<'a> {
     fn go_on(&mut self, letter: TileLetter, placements: &mut Vec<TilePlacement>,
              offset: isize, rack: Vec<RackLetter>, old_arc: &'a Arc, mut new_arc: Option<&'a Arc>) {
 
-        let mut row = self.row as isize;
-        let mut col = self.col as isize;
+        let mut row = self.row;
+        let mut col = self.col;
         match self.dir {
             Direction::LR => col += offset,
             Direction::TD => row += offset
@@ -353,25 +934,26 @@ impl<'a> MoveGenerator<'a> {
         if offset <= 0 {
             placements.insert(0, TilePlacement {
                 letter,
-                row: row as usize,
-                col: col as usize
+                row,
+                col
             });
 
             // if we have empty space on left and letter is an ending character, record play
             let empty_left = match self.dir {
                 Direction::TD => {
-                    row > 0 && self.solver.board[self.solver.get_index(row as usize - 1, col as usize)].is_none()
+                    row > self.solver.y_dim.min() && self.solver.board[self.solver.get_index(row - 1, col)].is_none()
                 },
                 Direction::LR => {
-                    col > 0 && self.solver.board[self.solver.get_index(row as usize, col as usize - 1)].is_none()
+                    col > self.solver.x_dim.min() && self.solver.board[self.solver.get_index(row, col - 1)].is_none()
                 }
             };
-            if old_arc.letter_set.contains(&letter.to_char()) && empty_left {
+            if old_arc.letter_set & char_mask(letter.to_char()) != 0 && empty_left {
                 self.moves.push(placements.clone());
             }
 
             if let Some(arc) = new_arc.take() {
-                if (self.dir == Direction::LR && col > 0) || (self.dir == Direction::TD && row > 0) {
+                if (self.dir == Direction::LR && col > self.solver.x_dim.min())
+                    || (self.dir == Direction::TD && row > self.solver.y_dim.min()) {
                     self.generate(placements, offset - 1, rack.clone(), arc);
                 }
 
@@ -384,27 +966,27 @@ impl<'a> MoveGenerator<'a> {
             // in this place, offset is > 0, so we are moving right
             placements.push(TilePlacement {
                 letter,
-                row: row as usize,
-                col: col as usize,
+                row,
+                col,
             });
 
             // if we have empty space on the right and letter is an ending character, record play
             let empty_right = match self.dir {
                 Direction::TD => {
-                    row as usize + 1 < self.solver.rows
-                        && self.solver.board[self.solver.get_index(row as usize + 1, col as usize)].is_none()
+                    row < self.solver.y_dim.max()
+                        && self.solver.board[self.solver.get_index(row + 1, col)].is_none()
                 },
                 Direction::LR => {
-                    col as usize + 1 < self.solver.cols
-                        && self.solver.board[self.solver.get_index(row as usize, col as usize + 1)].is_none()
+                    col < self.solver.x_dim.max()
+                        && self.solver.board[self.solver.get_index(row, col + 1)].is_none()
                 }
             };
-            if old_arc.letter_set.contains(&letter.to_char()) && empty_right {
+            if old_arc.letter_set & char_mask(letter.to_char()) != 0 && empty_right {
                 self.moves.push(placements.clone());
             }
 
-            if (self.dir == Direction::LR && col < self.solver.cols as isize - 1) ||
-                (self.dir == Direction::TD && row < self.solver.cols as isize - 1) {
+            if (self.dir == Direction::LR && col < self.solver.x_dim.max()) ||
+                (self.dir == Direction::TD && row < self.solver.y_dim.max()) {
                 if let Some(arc) = new_arc {
                     self.generate(placements, offset + 1, rack, arc);
                 }